@@ -0,0 +1,3 @@
+mod tui;
+
+pub use tui::{App, DiffApp};
@@ -0,0 +1,425 @@
+use std::io;
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use ratatui::{DefaultTerminal, Frame, buffer::Buffer, layout::{Alignment, Constraint, Direction, Layout, Rect}, style::{Color, Style, Stylize}, symbols::border, text::{Line, Span}, widgets::{Block, Gauge, Paragraph, Widget}};
+
+use crate::diff::{ProcessDiff, RegionDelta, RssChange};
+use crate::disasm::{self, LabelKind, Listing, X86Decoder};
+use crate::process::Process;
+
+const TOP_N_REGIONS: usize = 5;
+
+pub struct App {
+    process: Process,
+    exit: bool,
+    show_disasm: bool,
+    disasm_listing: Option<Result<Listing, String>>,
+}
+
+impl App {
+    pub fn new(process: Process) -> Self {
+        Self {
+            process,
+            exit: false,
+            show_disasm: false,
+            disasm_listing: None,
+        }
+    }
+
+    /// Disassembles the first executable region found, caching the result
+    /// so repeated toggles don't re-read `/proc/{pid}/mem`.
+    fn disasm_listing(&mut self) -> &Result<Listing, String> {
+        self.disasm_listing.get_or_insert_with(|| {
+            let region = self
+                .process
+                .memory_regions
+                .iter()
+                .find(|r| r.region().permissions().execute())
+                .ok_or_else(|| "no executable regions mapped".to_string())?;
+
+            let (start, end) = (region.region().start(), region.region().end());
+            let bytes = disasm::read_region_bytes(self.process.pid, start, end)
+                .map_err(|err| format!("failed to read {:#x}-{:#x}: {err}", start, end))?;
+
+            Ok(disasm::build_listing(&X86Decoder, start, &bytes))
+        })
+    }
+
+    /// Appends `libfoo.so!symbol+0x..` to a gauge row's name when this
+    /// group's executable region resolves to a known symbol.
+    fn annotate_region_name(&self, name: &str) -> String {
+        let group: Vec<_> = self
+            .process
+            .memory_regions
+            .iter()
+            .map(|r| r.region())
+            .filter(|r| r.path_name().map_or_else(String::new, |p| p.to_string()) == name)
+            .collect();
+
+        // Resolve against the group's executable region rather than
+        // whichever region happened to be listed first: that's often the
+        // file's offset-0 mapping, whose start lands on the ELF header
+        // rather than inside any code `resolve` can look up.
+        let Some(region) = group
+            .iter()
+            .find(|r| r.permissions().execute())
+            .or_else(|| group.first())
+        else {
+            return name.to_string();
+        };
+
+        match self.process.resolve(region.start()) {
+            Some((_, symbol, offset)) => format!("{name}!{symbol}+{offset:#x}"),
+            None => name.to_string(),
+        }
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        frame.render_widget(self, frame.area());
+    }
+
+    pub fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        while !self.exit {
+            terminal.draw(|frame| self.draw(frame))?;
+            self.handle_events()?;
+        }
+        Ok(())
+    }
+
+    fn handle_events(&mut self) -> io::Result<()> {
+        if event::poll(std::time::Duration::from_millis(100))? {
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
+                    self.handle_key_event(key)
+                }
+                Event::Resize(_, _) => {
+                    // Terminal is resized
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+          KeyCode::Char('q') | KeyCode::Char('Q') => {
+              self.exit = true;
+          }
+          KeyCode::Char('d') | KeyCode::Char('D') => {
+              self.show_disasm = !self.show_disasm;
+              if self.show_disasm {
+                  self.disasm_listing();
+              }
+          }
+          _ => {}
+        }
+    }
+}
+ 
+impl Widget for &App {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let title = Line::from(
+            vec![
+                format!(" Process: {}: ", self.process.pid).yellow(),
+                format!("{} ", self.process.cmd_line).white()
+            ]);
+        let instructions = Line::from(vec![
+            " Disasm ".into(),
+            "<D> ".blue().bold(),
+            " Quit ".into(),
+            "<Q> ".blue().bold(),
+        ]);
+
+
+        let block = Block::bordered()
+            .title(title.left_aligned())
+            .title_bottom(instructions.centered())
+            .border_set(border::THICK);
+
+        let inner_area = block.inner(area);
+        block.render(area, buf);
+
+        if self.show_disasm {
+            render_disasm(self.disasm_listing.as_ref(), inner_area, buf);
+            return;
+        }
+
+        let memory_totals= self.process.get_rss_totals();
+        let total: u64 = memory_totals.iter().map(|x| x.1).sum();
+        let top_n_totals:Vec<&(String, u64)> = memory_totals.iter().take(TOP_N_REGIONS).collect();
+                                                                                                                                                              
+        // Build constraints: [gauge, spacer, gauge, spacer, ...]                                                                                           
+        let mut constraints = Vec::new();                                                                                                                   
+        for _ in 0..top_n_totals.len() {                                                                                                                           
+            constraints.push(Constraint::Length(1)); // gauge row                                                                                           
+            constraints.push(Constraint::Length(1)); // spacer row                                                                                          
+        }    
+
+        // Create vertical chunks for each region's rss 
+        let gauge_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints).split(inner_area);
+
+    
+        let bar_colors = [
+            Color::Cyan,
+            Color::Green,
+            Color::Yellow,
+            Color::Magenta,
+            Color::Red,
+        ];
+
+        for (i, (name, rss_kb)) in top_n_totals.iter().enumerate() {
+            let chunk_idx = i * 2;
+            let color = bar_colors[i % bar_colors.len()];
+
+            // split each row, horizontally: [name | bar | amount]
+            let row_chunk = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(30),
+                Constraint::Percentage(50),
+                Constraint::Percentage(20)
+            ]).split(gauge_chunks[chunk_idx]);
+
+            // render the name (left), annotated with a resolved symbol when
+            // the group's executable region (or, failing that, its first
+            // region) starts inside a known function
+            let display_name = self.annotate_region_name(name);
+            let name_widget = Paragraph::new(display_name).alignment(Alignment::Left);
+            name_widget.render(row_chunk[0], buf);
+
+            // then the bar (middle)
+            let gauge = Gauge::default()
+            .gauge_style(Style::default()
+                .fg(color)
+                .bg(Color::DarkGray)
+            )
+            .ratio(*rss_kb as f64 / total as f64);
+
+            gauge.render(row_chunk[1], buf);
+
+            // then the memory amount (right)
+            let amount_widget = Paragraph::new(format!("{} kB", rss_kb)).alignment(Alignment::Right);
+            amount_widget.render(row_chunk[2], buf);
+        }
+    }
+}
+
+fn render_disasm(listing: Option<&Result<Listing, String>>, area: Rect, buf: &mut Buffer) {
+    let text = match listing {
+        None => "press <D> again to disassemble".to_string(),
+        Some(Err(err)) => format!("disasm failed: {err}"),
+        Some(Ok(listing)) => {
+            let mut lines = Vec::new();
+            for entry in &listing.entries {
+                if let Some(kind) = listing.labels.get(&entry.addr) {
+                    let tag = match kind {
+                        LabelKind::Func => "func",
+                        LabelKind::Label => "label",
+                    };
+                    lines.push(format!("{tag}_{:x}:", entry.addr));
+                }
+                lines.push(format!(
+                    "  {:x}: {} {}",
+                    entry.addr, entry.instruction.mnemonic, entry.instruction.operands
+                ));
+            }
+            for (addr, err) in &listing.errors {
+                lines.push(format!("  {addr:x}: ?? ({err})"));
+            }
+            lines.join("\n")
+        }
+    };
+
+    Paragraph::new(text).render(area, buf);
+}
+
+/// A standalone TUI view over a [`ProcessDiff`], opened by the `diff`
+/// command rather than by attaching to a live process.
+pub struct DiffApp {
+    diff: ProcessDiff,
+    exit: bool,
+}
+
+impl DiffApp {
+    pub fn new(diff: ProcessDiff) -> Self {
+        Self { diff, exit: false }
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        frame.render_widget(self, frame.area());
+    }
+
+    pub fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        while !self.exit {
+            terminal.draw(|frame| self.draw(frame))?;
+            self.handle_events()?;
+        }
+        Ok(())
+    }
+
+    fn handle_events(&mut self) -> io::Result<()> {
+        if event::poll(std::time::Duration::from_millis(100))? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press
+                    && matches!(key.code, KeyCode::Char('q') | KeyCode::Char('Q'))
+                {
+                    self.exit = true;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Widget for &DiffApp {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let title = Line::from(" Snapshot diff ".yellow());
+        let instructions = Line::from(vec![" Quit ".into(), "<Q> ".blue().bold()]);
+
+        let block = Block::bordered()
+            .title(title.left_aligned())
+            .title_bottom(instructions.centered())
+            .border_set(border::THICK);
+
+        let inner_area = block.inner(area);
+        block.render(area, buf);
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(30),
+                Constraint::Percentage(40),
+                Constraint::Percentage(30),
+            ])
+            .split(inner_area);
+
+        let appeared_disappeared = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(rows[0]);
+        render_named_list(&self.diff.appeared, "Appeared", Color::Red, appeared_disappeared[0], buf);
+        render_named_list(&self.diff.disappeared, "Disappeared", Color::Green, appeared_disappeared[1], buf);
+
+        let mut grown: Vec<&RegionDelta> = self
+            .diff
+            .groups
+            .iter()
+            .filter(|d| matches!(d.change, RssChange::Grown(_)))
+            .collect();
+        grown.sort_by_key(|d| std::cmp::Reverse(growth_amount(d.change)));
+
+        let mut shrunk: Vec<&RegionDelta> = self
+            .diff
+            .groups
+            .iter()
+            .filter(|d| matches!(d.change, RssChange::Shrunk(_)))
+            .collect();
+        shrunk.sort_by_key(|d| std::cmp::Reverse(growth_amount(d.change)));
+
+        let groups_columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(rows[1]);
+        render_delta_gauges(&grown, "Grown", Color::Red, groups_columns[0], buf);
+        render_delta_gauges(&shrunk, "Shrunk", Color::Green, groups_columns[1], buf);
+
+        render_region_list(&self.diff.regions, rows[2], buf);
+    }
+}
+
+fn growth_amount(change: RssChange) -> u64 {
+    match change {
+        RssChange::Grown(n) | RssChange::Shrunk(n) => n,
+        RssChange::Unchanged => 0,
+    }
+}
+
+/// Renders a heading, then up to `TOP_N_REGIONS` (name, rss_kb) rows, then
+/// a "+N more" line when the list was truncated.
+fn render_named_list(entries: &[(String, u64)], heading: &str, color: Color, area: Rect, buf: &mut Buffer) {
+    let mut lines = vec![Line::from(heading.bold())];
+    for (name, kb) in entries.iter().take(TOP_N_REGIONS) {
+        lines.push(Line::from(Span::styled(
+            format!("{name} ({kb} kB)"),
+            Style::default().fg(color),
+        )));
+    }
+    if entries.len() > TOP_N_REGIONS {
+        lines.push(Line::from(format!("+{} more", entries.len() - TOP_N_REGIONS)));
+    }
+    Paragraph::new(lines).render(area, buf);
+}
+
+/// Renders `deltas` as `[name | gauge | amount]` rows, reusing the same
+/// gauge-row layout [`App`] uses for its RSS totals, plus a "+N more" row
+/// when truncated to `TOP_N_REGIONS`.
+fn render_delta_gauges(deltas: &[&RegionDelta], heading: &str, color: Color, area: Rect, buf: &mut Buffer) {
+    let shown: Vec<&&RegionDelta> = deltas.iter().take(TOP_N_REGIONS).collect();
+    let remaining = deltas.len().saturating_sub(shown.len());
+    let max_amount = shown.iter().map(|d| growth_amount(d.change)).max().unwrap_or(0).max(1);
+
+    let mut constraints = vec![Constraint::Length(1)];
+    for _ in &shown {
+        constraints.push(Constraint::Length(1));
+    }
+    if remaining > 0 {
+        constraints.push(Constraint::Length(1));
+    }
+    let rows = Layout::default().direction(Direction::Vertical).constraints(constraints).split(area);
+
+    Paragraph::new(Line::from(heading.bold())).render(rows[0], buf);
+
+    for (i, delta) in shown.iter().enumerate() {
+        let row = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(40), Constraint::Percentage(40), Constraint::Percentage(20)])
+            .split(rows[i + 1]);
+
+        Paragraph::new(delta.name.clone()).alignment(Alignment::Left).render(row[0], buf);
+
+        Gauge::default()
+            .gauge_style(Style::default().fg(color).bg(Color::DarkGray))
+            .ratio(growth_amount(delta.change) as f64 / max_amount as f64)
+            .render(row[1], buf);
+
+        Paragraph::new(format!("{} kB", growth_amount(delta.change)))
+            .alignment(Alignment::Right)
+            .render(row[2], buf);
+    }
+
+    if remaining > 0 {
+        Paragraph::new(format!("+{remaining} more")).render(rows[rows.len() - 1], buf);
+    }
+}
+
+/// Renders every matched region's before/after delta, sorted by magnitude,
+/// with a "+N more" row when truncated to `TOP_N_REGIONS`.
+fn render_region_list(regions: &[RegionDelta], area: Rect, buf: &mut Buffer) {
+    let mut sorted: Vec<&RegionDelta> = regions.iter().collect();
+    sorted.sort_by_key(|d| std::cmp::Reverse(growth_amount(d.change)));
+
+    let mut lines = vec![Line::from("Regions".bold())];
+    for delta in sorted.iter().take(TOP_N_REGIONS) {
+        let color = match delta.change {
+            RssChange::Grown(_) => Color::Red,
+            RssChange::Shrunk(_) => Color::Green,
+            RssChange::Unchanged => Color::DarkGray,
+        };
+        let sign = match delta.change {
+            RssChange::Grown(_) => "+",
+            RssChange::Shrunk(_) => "-",
+            RssChange::Unchanged => "",
+        };
+        lines.push(Line::from(Span::styled(
+            format!("{} ({sign}{} kB)", delta.name, growth_amount(delta.change)),
+            Style::default().fg(color),
+        )));
+    }
+    if sorted.len() > TOP_N_REGIONS {
+        lines.push(Line::from(format!("+{} more", sorted.len() - TOP_N_REGIONS)));
+    }
+    Paragraph::new(lines).render(area, buf);
+}
\ No newline at end of file
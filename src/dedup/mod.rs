@@ -0,0 +1,144 @@
+//! Content-hash dedup report for live process memory.
+//!
+//! Hashes each readable region page by page to find candidate duplicates,
+//! the same idea decomp-toolkit uses to detect identical split files via a
+//! checksum, applied here to reveal KSM-mergeable or accidentally
+//! duplicated pages. A hash match is only a candidate: pages that collide
+//! are compared byte-for-byte before being reported as a group, so a
+//! 64-bit hash collision can't inflate the wasted-bytes total.
+
+use std::collections::HashMap;
+use std::io;
+
+use crate::disasm::read_region_bytes;
+use crate::hash::fnv1a64;
+use crate::process::Process;
+
+const PAGE_SIZE: u64 = 4096;
+
+/// One page-sized, byte-identical copy found somewhere in the process.
+#[derive(Debug, Clone)]
+pub struct PageLocation {
+    pub path_name: String,
+    pub addr: u64,
+}
+
+/// A set of pages that all hash the same, i.e. are byte-identical.
+#[derive(Debug)]
+pub struct DedupGroup {
+    pub hash: u64,
+    pub locations: Vec<PageLocation>,
+}
+
+impl DedupGroup {
+    /// Bytes that could be reclaimed by merging every copy but the first.
+    pub fn wasted_bytes(&self) -> u64 {
+        (self.locations.len() as u64 - 1) * PAGE_SIZE
+    }
+}
+
+pub struct DedupReport {
+    /// Only groups with more than one location, sorted by wasted bytes
+    /// descending.
+    pub groups: Vec<DedupGroup>,
+    pub wasted_bytes: u64,
+}
+
+/// Reads every readable region of `pid` and groups its pages by content
+/// hash. Regions without `Permissions.read` are skipped, as are regions
+/// that fail to read (e.g. unmapped since the `Process` was parsed) rather
+/// than aborting the whole pass.
+pub fn find_duplicate_pages(pid: u32, process: &Process) -> io::Result<DedupReport> {
+    let mut by_hash: HashMap<u64, Vec<(PageLocation, Vec<u8>)>> = HashMap::new();
+
+    for detailed in &process.memory_regions {
+        let region = detailed.region();
+        if !region.permissions().read() {
+            continue;
+        }
+
+        let Ok(bytes) = read_region_bytes(pid, region.start(), region.end()) else {
+            continue;
+        };
+
+        let path_name = region
+            .path_name()
+            .map_or_else(String::new, |p| p.to_string());
+
+        for (page_index, page) in bytes.chunks(PAGE_SIZE as usize).enumerate() {
+            let addr = region.start() + page_index as u64 * PAGE_SIZE;
+            let location = PageLocation {
+                path_name: path_name.clone(),
+                addr,
+            };
+            by_hash.entry(fnv1a64(page)).or_default().push((location, page.to_vec()));
+        }
+    }
+
+    let mut groups: Vec<DedupGroup> = by_hash
+        .into_iter()
+        .flat_map(|(hash, entries)| split_by_identical_bytes(hash, entries))
+        .filter(|group| group.locations.len() > 1)
+        .collect();
+    groups.sort_by_key(|group| std::cmp::Reverse(group.wasted_bytes()));
+
+    let wasted_bytes = groups.iter().map(DedupGroup::wasted_bytes).sum();
+
+    Ok(DedupReport { groups, wasted_bytes })
+}
+
+/// Splits one hash bucket into groups that are actually byte-identical, so
+/// a hash collision between two different pages doesn't get reported as a
+/// duplicate.
+fn split_by_identical_bytes(hash: u64, entries: Vec<(PageLocation, Vec<u8>)>) -> Vec<DedupGroup> {
+    let mut groups: Vec<(Vec<u8>, Vec<PageLocation>)> = Vec::new();
+
+    for (location, page) in entries {
+        match groups.iter_mut().find(|(bytes, _)| *bytes == page) {
+            Some((_, locations)) => locations.push(location),
+            None => groups.push((page, vec![location])),
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(_, locations)| DedupGroup { hash, locations })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn location(addr: u64) -> PageLocation {
+        PageLocation { path_name: String::new(), addr }
+    }
+
+    #[test]
+    fn groups_pages_that_share_a_hash_and_are_byte_identical() {
+        let entries = vec![
+            (location(0x1000), vec![1u8; 4096]),
+            (location(0x2000), vec![1u8; 4096]),
+            (location(0x3000), vec![1u8; 4096]),
+        ];
+
+        let groups = split_by_identical_bytes(42, entries);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].locations.len(), 3);
+    }
+
+    #[test]
+    fn does_not_merge_a_hash_collision_between_different_pages() {
+        let mut page_b = vec![0u8; 4096];
+        page_b[0] = 1;
+
+        let entries = vec![
+            (location(0x1000), vec![0u8; 4096]),
+            (location(0x2000), page_b),
+        ];
+
+        let groups = split_by_identical_bytes(42, entries);
+        assert_eq!(groups.len(), 2);
+        assert!(groups.iter().all(|g| g.locations.len() == 1));
+    }
+}
@@ -0,0 +1,165 @@
+//! Diffing two [`Process`] snapshots to spot growth, shrinkage, and
+//! regions that appeared or disappeared between them.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::process::Process;
+use crate::process::memory::region::{DetailedMemoryRegion, MemoryRegion, PathType};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RssChange {
+    Grown(u64),
+    Shrunk(u64),
+    Unchanged,
+}
+
+/// RSS, in kB, for one matched region or `get_rss_totals` group, before and
+/// after.
+#[derive(Debug, Clone)]
+pub struct RegionDelta {
+    pub name: String,
+    pub before_kb: u64,
+    pub after_kb: u64,
+    pub change: RssChange,
+}
+
+pub struct ProcessDiff {
+    /// Regions present in both snapshots, matched by [`match_key`].
+    pub regions: Vec<RegionDelta>,
+    /// Regions only present in `after`, as (name, rss_kb).
+    pub appeared: Vec<(String, u64)>,
+    /// Regions only present in `before`, as (name, rss_kb).
+    pub disappeared: Vec<(String, u64)>,
+    /// Per-`get_rss_totals`-group delta.
+    pub groups: Vec<RegionDelta>,
+}
+
+/// A region's identity across two snapshots of the same process.
+///
+/// File-backed regions are matched by `(path, offset)`, which is stable
+/// across ASLR-induced address shuffling between runs. Anonymous/kernel
+/// regions have no such identity, so they fall back to the address range
+/// they occupy, which is only stable when ASLR is disabled.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum MatchKey {
+    Inode(String, u64),
+    AddrRange(u64, u64),
+}
+
+fn match_key(region: &MemoryRegion) -> MatchKey {
+    match region.path_name() {
+        Some(PathType::File(_)) | Some(PathType::Deleted(_)) => {
+            MatchKey::Inode(region.path_name().unwrap().to_string(), region.offset())
+        }
+        _ => MatchKey::AddrRange(region.start(), region.end()),
+    }
+}
+
+fn display_name(region: &MemoryRegion) -> String {
+    region.path_name().map_or_else(String::new, |p| p.to_string())
+}
+
+fn classify(before_kb: u64, after_kb: u64) -> RssChange {
+    if after_kb > before_kb {
+        RssChange::Grown(after_kb - before_kb)
+    } else if after_kb < before_kb {
+        RssChange::Shrunk(before_kb - after_kb)
+    } else {
+        RssChange::Unchanged
+    }
+}
+
+pub fn diff_processes(before: &Process, after: &Process) -> ProcessDiff {
+    let mut before_by_key: HashMap<MatchKey, &DetailedMemoryRegion> = HashMap::new();
+    for region in &before.memory_regions {
+        before_by_key.insert(match_key(region.region()), region);
+    }
+
+    let mut matched = HashSet::new();
+    let mut regions = Vec::new();
+    let mut appeared = Vec::new();
+
+    for after_region in &after.memory_regions {
+        let key = match_key(after_region.region());
+        let name = display_name(after_region.region());
+
+        match before_by_key.get(&key) {
+            Some(before_region) => {
+                matched.insert(key);
+                let before_kb = before_region.rss_kb();
+                let after_kb = after_region.rss_kb();
+                regions.push(RegionDelta {
+                    name,
+                    before_kb,
+                    after_kb,
+                    change: classify(before_kb, after_kb),
+                });
+            }
+            None => appeared.push((name, after_region.rss_kb())),
+        }
+    }
+
+    let disappeared = before
+        .memory_regions
+        .iter()
+        .filter(|region| !matched.contains(&match_key(region.region())))
+        .map(|region| (display_name(region.region()), region.rss_kb()))
+        .collect();
+
+    ProcessDiff {
+        regions,
+        appeared,
+        disappeared,
+        groups: diff_group_totals(before, after),
+    }
+}
+
+fn diff_group_totals(before: &Process, after: &Process) -> Vec<RegionDelta> {
+    let before_totals: HashMap<String, u64> = before.get_rss_totals().into_iter().collect();
+    let after_totals: HashMap<String, u64> = after.get_rss_totals().into_iter().collect();
+
+    let mut names: Vec<&String> = before_totals.keys().chain(after_totals.keys()).collect();
+    names.sort();
+    names.dedup();
+
+    names
+        .into_iter()
+        .map(|name| {
+            let before_kb = *before_totals.get(name).unwrap_or(&0);
+            let after_kb = *after_totals.get(name).unwrap_or(&0);
+            RegionDelta {
+                name: name.clone(),
+                before_kb,
+                after_kb,
+                change: classify(before_kb, after_kb),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn classify_grown_shrunk_unchanged() {
+        assert_eq!(classify(10, 15), RssChange::Grown(5));
+        assert_eq!(classify(15, 10), RssChange::Shrunk(5));
+        assert_eq!(classify(10, 10), RssChange::Unchanged);
+    }
+
+    #[test]
+    fn match_key_prefers_path_and_offset_for_file_backed_regions() {
+        let a = MemoryRegion::from_str("00400000-00401000 r-xp 00001000 08:01 12345 /bin/true").unwrap();
+        let b = MemoryRegion::from_str("7f0000000000-7f0000001000 r-xp 00001000 08:01 12345 /bin/true").unwrap();
+        assert_eq!(match_key(&a), match_key(&b));
+    }
+
+    #[test]
+    fn match_key_falls_back_to_address_range_for_anonymous_regions() {
+        let region = MemoryRegion::from_str("00400000-00401000 rw-p 00000000 00:00 0 ").unwrap();
+        assert_eq!(match_key(&region), MatchKey::AddrRange(0x400000, 0x401000));
+    }
+}
@@ -0,0 +1,28 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DecodeError {
+    #[error("invalid opcode {0:#04x} at offset {1:#x}")]
+    InvalidInstruction(u8, usize),
+}
+
+/// One decoded instruction.
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    pub mnemonic: String,
+    pub operands: String,
+    /// Number of bytes consumed from the input slice.
+    pub len: usize,
+    /// Absolute virtual address of a relative branch/call target, if any.
+    pub branch_target: Option<u64>,
+}
+
+/// A streaming, linear-sweep decoder for one instruction-set architecture.
+///
+/// Implementations consume bytes from the front of `bytes` (by reassigning
+/// it to the remainder) and report the instruction that started at `addr`.
+/// Relative branch/call targets are resolved to absolute addresses here,
+/// since only the decoder knows the operand encoding.
+pub trait InstructionDecoder {
+    fn decode(&self, bytes: &mut &[u8], addr: u64) -> Result<Instruction, DecodeError>;
+}
@@ -0,0 +1,111 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use super::decoder::{DecodeError, Instruction, InstructionDecoder};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LabelKind {
+    Func,
+    Label,
+}
+
+pub struct ListingEntry {
+    pub addr: u64,
+    pub instruction: Instruction,
+}
+
+pub struct Listing {
+    pub entries: Vec<ListingEntry>,
+    pub labels: BTreeMap<u64, LabelKind>,
+    pub errors: Vec<(u64, DecodeError)>,
+}
+
+/// Linear-sweep disassembly of `bytes`, which is mapped starting at virtual
+/// address `base`.
+///
+/// Decoding starts at `base` and follows every in-region relative
+/// branch/call target it discovers, via a work queue, recording each target
+/// in the returned label map so callers can print it inline. A target that
+/// decodes to an invalid opcode stops that sweep without aborting the
+/// others.
+pub fn build_listing(decoder: &dyn InstructionDecoder, base: u64, bytes: &[u8]) -> Listing {
+    let end = base + bytes.len() as u64;
+
+    let mut labels = BTreeMap::new();
+    labels.insert(base, LabelKind::Func);
+
+    let mut entries: BTreeMap<u64, Instruction> = BTreeMap::new();
+    let mut errors = Vec::new();
+    let mut queue = VecDeque::from([base]);
+
+    while let Some(start) = queue.pop_front() {
+        if start < base || start >= end || entries.contains_key(&start) {
+            continue;
+        }
+
+        let mut pc = start;
+        while pc < end && !entries.contains_key(&pc) {
+            let offset = (pc - base) as usize;
+            let mut cursor = &bytes[offset..];
+
+            match decoder.decode(&mut cursor, pc) {
+                Ok(instruction) => {
+                    let len = instruction.len as u64;
+                    if let Some(target) = instruction.branch_target {
+                        if target >= base && target < end {
+                            labels.entry(target).or_insert(LabelKind::Label);
+                            queue.push_back(target);
+                        }
+                    }
+                    entries.insert(pc, instruction);
+                    pc += len;
+                }
+                Err(err) => {
+                    errors.push((pc, err));
+                    break;
+                }
+            }
+        }
+    }
+
+    Listing {
+        entries: entries
+            .into_iter()
+            .map(|(addr, instruction)| ListingEntry { addr, instruction })
+            .collect(),
+        labels,
+        errors,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disasm::X86Decoder;
+
+    #[test]
+    fn follows_a_forward_jump_and_labels_its_target() {
+        // push rbp; jmp +1 (skips the nop); nop; ret
+        let bytes = [0x55, 0xeb, 0x01, 0x90, 0xc3];
+        let listing = build_listing(&X86Decoder, 0x1000, &bytes);
+
+        let addrs: Vec<u64> = listing.entries.iter().map(|e| e.addr).collect();
+        assert_eq!(addrs, vec![0x1000, 0x1001, 0x1003, 0x1004]);
+
+        assert_eq!(listing.labels.get(&0x1000), Some(&LabelKind::Func));
+        assert_eq!(listing.labels.get(&0x1004), Some(&LabelKind::Label));
+        assert!(listing.errors.is_empty());
+    }
+
+    #[test]
+    fn records_an_error_without_aborting_other_sweeps() {
+        // jmp +1 (skips the bad byte); an opcode this decoder doesn't know; ret
+        let bytes = [0xeb, 0x01, 0xff, 0xc3];
+        let listing = build_listing(&X86Decoder, 0x2000, &bytes);
+
+        assert_eq!(listing.errors.len(), 1);
+        assert_eq!(listing.errors[0].0, 0x2002);
+
+        let addrs: Vec<u64> = listing.entries.iter().map(|e| e.addr).collect();
+        assert_eq!(addrs, vec![0x2000, 0x2003]);
+    }
+}
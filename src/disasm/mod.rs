@@ -0,0 +1,15 @@
+//! Linear-sweep disassembly of executable process memory.
+//!
+//! [`decoder`] defines the architecture-agnostic [`InstructionDecoder`]
+//! trait, [`listing`] drives it over a region's bytes with a work queue to
+//! discover labels, and [`procmem`] pulls the raw bytes out of
+//! `/proc/{pid}/mem`.
+
+mod decoder;
+mod listing;
+mod procmem;
+mod x86;
+
+pub use listing::{LabelKind, Listing, build_listing};
+pub use procmem::read_region_bytes;
+pub use x86::X86Decoder;
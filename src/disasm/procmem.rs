@@ -0,0 +1,14 @@
+use std::fs::File;
+use std::io;
+use std::os::unix::fs::FileExt;
+
+/// Reads `[start, end)` of `pid`'s virtual address space by `pread`-ing
+/// `/proc/{pid}/mem`, the only way to get at the bytes backing a mapping
+/// (the region itself may not correspond to a readable file on disk, e.g.
+/// JIT'd code or a stripped binary mapped over a deleted file).
+pub fn read_region_bytes(pid: u32, start: u64, end: u64) -> io::Result<Vec<u8>> {
+    let file = File::open(format!("/proc/{pid}/mem"))?;
+    let mut buf = vec![0u8; (end - start) as usize];
+    file.read_exact_at(&mut buf, start)?;
+    Ok(buf)
+}
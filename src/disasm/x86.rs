@@ -0,0 +1,220 @@
+use super::decoder::{DecodeError, Instruction, InstructionDecoder};
+
+/// A minimal x86-64 decoder covering the opcodes this tool needs to follow
+/// control flow (unconditional/conditional jumps, calls and ret) plus the
+/// handful of prologue encodings that show up at the start of virtually
+/// every function: a REX prefix, `push r64`, and `endbr64`. Anything else is
+/// reported as [`DecodeError::InvalidInstruction`] rather than guessed at,
+/// since a wrong instruction length would desync the rest of the sweep.
+pub struct X86Decoder;
+
+impl InstructionDecoder for X86Decoder {
+    fn decode(&self, bytes: &mut &[u8], addr: u64) -> Result<Instruction, DecodeError> {
+        let opcode = *bytes.first().ok_or(DecodeError::InvalidInstruction(0, 0))?;
+
+        // A REX prefix (0x40-0x4f) just widens the operands of the
+        // instruction that follows; for the subset we decode below it
+        // doesn't change the length or mnemonic, so skip over it and decode
+        // the rest at `addr` as if the prefix weren't there.
+        if (0x40..=0x4f).contains(&opcode) {
+            let rest = &bytes[1..];
+            if rest.is_empty() {
+                return Err(DecodeError::InvalidInstruction(opcode, 0));
+            }
+            let mut cursor = rest;
+            let mut instruction = decode_unprefixed(&mut cursor, addr, 1)?;
+            instruction.len += 1;
+            *bytes = &bytes[instruction.len..];
+            return Ok(instruction);
+        }
+
+        let instruction = decode_unprefixed(bytes, addr, 0)?;
+        *bytes = &bytes[instruction.len..];
+        Ok(instruction)
+    }
+}
+
+/// Decodes one instruction starting at `bytes[0]`, which corresponds to
+/// `addr` in the running process. `prefix_len` is the number of prefix bytes
+/// (e.g. a REX byte) already consumed from the original instruction by the
+/// caller: it is added to `InvalidInstruction`'s offset so that points at
+/// the actual opcode byte rather than 0, and to the instruction length when
+/// resolving a relative branch target.
+fn decode_unprefixed(bytes: &mut &[u8], addr: u64, prefix_len: usize) -> Result<Instruction, DecodeError> {
+    let opcode = *bytes
+        .first()
+        .ok_or(DecodeError::InvalidInstruction(0, prefix_len))?;
+
+    Ok(match opcode {
+        0x90 => Instruction {
+            mnemonic: "nop".into(),
+            operands: String::new(),
+            len: 1,
+            branch_target: None,
+        },
+        0xc3 => Instruction {
+            mnemonic: "ret".into(),
+            operands: String::new(),
+            len: 1,
+            branch_target: None,
+        },
+        0x50..=0x57 => Instruction {
+            mnemonic: "push".into(),
+            operands: REG_NAMES[(opcode - 0x50) as usize].into(),
+            len: 1,
+            branch_target: None,
+        },
+        0x58..=0x5f => Instruction {
+            mnemonic: "pop".into(),
+            operands: REG_NAMES[(opcode - 0x58) as usize].into(),
+            len: 1,
+            branch_target: None,
+        },
+        0xe8 | 0xe9 => {
+            let rel = read_i32(bytes, 1, prefix_len)?;
+            let len = 5;
+            let target = branch_target(addr, prefix_len + len, rel as i64);
+            Instruction {
+                mnemonic: if opcode == 0xe8 { "call".into() } else { "jmp".into() },
+                operands: format!("{:#x}", target),
+                len,
+                branch_target: Some(target),
+            }
+        }
+        0xeb => {
+            let rel = read_i8(bytes, 1, prefix_len)?;
+            let len = 2;
+            let target = branch_target(addr, prefix_len + len, rel as i64);
+            Instruction {
+                mnemonic: "jmp".into(),
+                operands: format!("{:#x}", target),
+                len,
+                branch_target: Some(target),
+            }
+        }
+        0x70..=0x7f => {
+            let rel = read_i8(bytes, 1, prefix_len)?;
+            let len = 2;
+            let target = branch_target(addr, prefix_len + len, rel as i64);
+            Instruction {
+                mnemonic: format!("j{}", CONDITION_NAMES[(opcode - 0x70) as usize]),
+                operands: format!("{:#x}", target),
+                len,
+                branch_target: Some(target),
+            }
+        }
+        // endbr64 (f3 0f 1e fa): a CET landing-pad marker that's a no-op
+        // without hardware CET enabled, but ubiquitous at function entry
+        // with modern toolchains.
+        0xf3 if bytes.get(1..4) == Some(&[0x0f, 0x1e, 0xfa]) => Instruction {
+            mnemonic: "endbr64".into(),
+            operands: String::new(),
+            len: 4,
+            branch_target: None,
+        },
+        // Two-byte jcc rel32 (0f 80-8f).
+        0x0f if matches!(bytes.get(1), Some(0x80..=0x8f)) => {
+            let cc = bytes[1] - 0x80;
+            let rel = read_i32(bytes, 2, prefix_len)?;
+            let len = 6;
+            let target = branch_target(addr, prefix_len + len, rel as i64);
+            Instruction {
+                mnemonic: format!("j{}", CONDITION_NAMES[cc as usize]),
+                operands: format!("{:#x}", target),
+                len,
+                branch_target: Some(target),
+            }
+        }
+        0x0f => {
+            let second = *bytes
+                .get(1)
+                .ok_or(DecodeError::InvalidInstruction(opcode, prefix_len))?;
+            return Err(DecodeError::InvalidInstruction(second, prefix_len + 1));
+        }
+        other => return Err(DecodeError::InvalidInstruction(other, prefix_len)),
+    })
+}
+
+fn branch_target(addr: u64, len: usize, rel: i64) -> u64 {
+    (addr.wrapping_add(len as u64) as i64).wrapping_add(rel) as u64
+}
+
+const REG_NAMES: [&str; 8] = ["rax", "rcx", "rdx", "rbx", "rsp", "rbp", "rsi", "rdi"];
+
+const CONDITION_NAMES: [&str; 16] = [
+    "o", "no", "b", "ae", "e", "ne", "be", "a", "s", "ns", "p", "np", "l", "ge", "le", "g",
+];
+
+/// Reads the byte at `bytes[offset]`. `prefix_len` is added to the offset
+/// reported in any error, so it reflects the position within the whole
+/// instruction (including prefix bytes already stripped by the caller).
+fn read_i8(bytes: &[u8], offset: usize, prefix_len: usize) -> Result<i8, DecodeError> {
+    bytes
+        .get(offset)
+        .map(|&b| b as i8)
+        .ok_or(DecodeError::InvalidInstruction(bytes[0], prefix_len + offset))
+}
+
+fn read_i32(bytes: &[u8], offset: usize, prefix_len: usize) -> Result<i32, DecodeError> {
+    let word = bytes
+        .get(offset..offset + 4)
+        .ok_or(DecodeError::InvalidInstruction(bytes[0], prefix_len + offset))?;
+    Ok(i32::from_le_bytes(word.try_into().unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode_one(mut bytes: &[u8], addr: u64) -> Instruction {
+        X86Decoder.decode(&mut bytes, addr).unwrap()
+    }
+
+    #[test]
+    fn decodes_common_prologue_bytes() {
+        // push rbp; endbr64; ret
+        let insn = decode_one(&[0x55], 0x1000);
+        assert_eq!(insn.mnemonic, "push");
+        assert_eq!(insn.operands, "rbp");
+        assert_eq!(insn.len, 1);
+
+        let insn = decode_one(&[0xf3, 0x0f, 0x1e, 0xfa], 0x1000);
+        assert_eq!(insn.mnemonic, "endbr64");
+        assert_eq!(insn.len, 4);
+
+        let insn = decode_one(&[0xc3], 0x1000);
+        assert_eq!(insn.mnemonic, "ret");
+    }
+
+    #[test]
+    fn decodes_rex_prefixed_instruction() {
+        // 0x48 (REX.W) + 0x55 (push rbp) behaves like push rbp but is 2 bytes long.
+        let insn = decode_one(&[0x48, 0x55], 0x1000);
+        assert_eq!(insn.mnemonic, "push");
+        assert_eq!(insn.len, 2);
+    }
+
+    #[test]
+    fn decodes_near_jcc_rel32() {
+        let mut rel = (-6i32).to_le_bytes().to_vec();
+        let mut bytes = vec![0x0f, 0x84];
+        bytes.append(&mut rel);
+
+        let insn = decode_one(&bytes, 0x2000);
+        assert_eq!(insn.mnemonic, "je");
+        assert_eq!(insn.len, 6);
+        assert_eq!(insn.branch_target, Some(0x2000));
+    }
+
+    #[test]
+    fn reports_offset_of_bad_opcode_not_zero() {
+        let mut bytes: &[u8] = &[0x48, 0x0f, 0x04];
+        let err = X86Decoder.decode(&mut bytes, 0x1000).unwrap_err();
+        match err {
+            DecodeError::InvalidInstruction(opcode, offset) => {
+                assert_eq!(opcode, 0x04);
+                assert_eq!(offset, 2);
+            }
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! Shared non-cryptographic hashing used where collisions only cost an
+//! extra byte comparison, not correctness: comparing a serialized snapshot
+//! against what's already on disk, and grouping byte-identical memory
+//! pages.
+
+/// FNV-1a over `data`. Every hash match is confirmed byte-for-byte (dedup
+/// before grouping pages, `write_snapshot` before skipping a write), so a
+/// collision can't merge different pages or skip a changed snapshot, and
+/// speed wins over cryptographic strength.
+pub(crate) fn fnv1a64(data: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    data.iter()
+        .fold(OFFSET_BASIS, |hash, &byte| (hash ^ byte as u64).wrapping_mul(PRIME))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_input_is_the_fnv_offset_basis() {
+        assert_eq!(fnv1a64(&[]), 0xcbf29ce484222325);
+    }
+
+    #[test]
+    fn is_deterministic_and_content_sensitive() {
+        assert_eq!(fnv1a64(b"hello"), fnv1a64(b"hello"));
+        assert_ne!(fnv1a64(b"hello"), fnv1a64(b"hellp"));
+    }
+}
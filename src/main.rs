@@ -1,36 +1,127 @@
-use std::fs;
+use std::path::{Path, PathBuf};
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
+use color_eyre::eyre::Result;
 
-use crate::memory::MemoryRegion;
+use crate::app::{App, DiffApp};
+use crate::process::Process;
 
-mod memory;
+mod app;
+mod dedup;
+mod diff;
+mod disasm;
+mod hash;
+mod process;
+mod snapshot;
+mod symbols;
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 struct Args {
     #[arg(short, long)]
-    pid: u32,
+    pid: Option<u32>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Write a binary snapshot of a live process's memory map to disk, for
+    /// later comparison with `diff`.
+    Capture { pid: u32, out: PathBuf },
+
+    /// Compare two snapshots taken from the same process and show per-region RSS growth/shrinkage.
+    Diff { before: PathBuf, after: PathBuf },
+
+    /// Find byte-identical pages across a live process's readable regions.
+    Dedup {
+        pid: u32,
+
+        /// Print only the aggregate wasted total, for scripting.
+        #[arg(long)]
+        quiet: bool,
+    },
 }
 
-fn main() {
+fn main() -> Result<()> {
     let args = Args::parse();
-    let regions = read_maps(args.pid).unwrap_or_else(|err| 
-        panic!("Unable to read file: {}", err)
-    );
 
-    for region in regions {
-        println!("{}",region);
+    match args.command {
+        Some(Command::Capture { pid, out }) => run_capture(pid, &out),
+        Some(Command::Diff { before, after }) => run_diff(&before, &after),
+        Some(Command::Dedup { pid, quiet }) => run_dedup(pid, quiet),
+        None => {
+            let pid = args.pid.unwrap_or_else(|| panic!("--pid is required"));
+            run_live(pid)
+        }
     }
 }
 
+fn run_live(pid: u32) -> Result<()> {
+    color_eyre::install()?;
+
+    let process = Process::try_from(pid)?;
+    let mut terminal = ratatui::init();
+    let result = App::new(process).run(&mut terminal);
+    ratatui::restore();
+    result.map_err(Into::into)
+}
+
+fn run_capture(pid: u32, out: &Path) -> Result<()> {
+    let process = Process::try_from(pid)?;
+
+    // If `out` already holds a snapshot, read it first so `write_snapshot`
+    // can detect whether something else touched the file since.
+    let read_at = match out.exists() {
+        true => Some(snapshot::read_snapshot(out)?.1),
+        false => None,
+    };
+
+    match snapshot::write_snapshot(out, &process, read_at)? {
+        snapshot::SnapshotWrite::Written => println!("wrote snapshot to {}", out.display()),
+        snapshot::SnapshotWrite::UnchangedSkipped => {
+            println!("{} already matches this process's memory map; left untouched", out.display())
+        }
+    }
+
+    Ok(())
+}
+
+fn run_diff(before: &Path, after: &Path) -> Result<()> {
+    color_eyre::install()?;
+
+    let (before, _) = snapshot::read_snapshot(before)?;
+    let (after, _) = snapshot::read_snapshot(after)?;
+    let process_diff = diff::diff_processes(&before, &after);
+
+    let mut terminal = ratatui::init();
+    let result = DiffApp::new(process_diff).run(&mut terminal);
+    ratatui::restore();
+    result.map_err(Into::into)
+}
+
+fn run_dedup(pid: u32, quiet: bool) -> Result<()> {
+    let process = Process::try_from(pid)?;
+    let report = dedup::find_duplicate_pages(pid, &process)?;
+
+    if quiet {
+        println!("{}", report.wasted_bytes);
+        return Ok(());
+    }
+
+    for group in &report.groups {
+        println!(
+            "hash {:016x}: {} bytes duplicated across {} copies",
+            group.hash,
+            group.wasted_bytes(),
+            group.locations.len()
+        );
+        for location in &group.locations {
+            println!("  {:#x} {}", location.addr, location.path_name);
+        }
+    }
+    println!("wasted: {} bytes", report.wasted_bytes);
 
-fn read_maps(pid: u32) -> Result<Vec<MemoryRegion>, Box<dyn std::error::Error>> {
-    let content = fs::read_to_string(format!("/proc/{pid}/maps"))?;
-    
-    content
-        .lines()
-        .map(|line| line.parse())
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(Into::into)
+    Ok(())
 }
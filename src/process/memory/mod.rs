@@ -0,0 +1,3 @@
+pub mod region;
+
+pub use region::MemoryRegion;
@@ -1,8 +1,13 @@
 use std::fmt;
+use std::io;
 use std::path::PathBuf;
 use std::str::FromStr;
 use thiserror::Error;
 
+use crate::snapshot::format::{
+    FromReader, ToWriter, read_string, read_u64, read_u8, write_string, write_u64, write_u8,
+};
+
 #[derive(Debug, Error)]
 pub enum MemoryParseError {
     #[error("missing field: {0}")]
@@ -42,6 +47,39 @@ impl FromStr for Permissions {
     }
 }
 
+impl Permissions {
+    pub(crate) fn execute(&self) -> bool {
+        self.execute
+    }
+
+    pub(crate) fn read(&self) -> bool {
+        self.read
+    }
+}
+
+impl ToWriter for Permissions {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        let mut bits = 0u8;
+        if self.read { bits |= 0b0001; }
+        if self.write { bits |= 0b0010; }
+        if self.execute { bits |= 0b0100; }
+        if self.shared { bits |= 0b1000; }
+        write_u8(writer, bits)
+    }
+}
+
+impl FromReader for Permissions {
+    fn from_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let bits = read_u8(reader)?;
+        Ok(Permissions {
+            read: bits & 0b0001 != 0,
+            write: bits & 0b0010 != 0,
+            execute: bits & 0b0100 != 0,
+            shared: bits & 0b1000 != 0,
+        })
+    }
+}
+
 impl fmt::Display for Permissions {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -55,6 +93,7 @@ impl fmt::Display for Permissions {
     }
 }
 
+#[derive(Clone)]
 pub enum PathType {
     // Actual file on disk
     File(PathBuf),
@@ -95,6 +134,57 @@ impl FromStr for PathType {
     }
 }
 
+const PATH_TYPE_ANONYMOUS: u8 = 0;
+const PATH_TYPE_HEAP: u8 = 1;
+const PATH_TYPE_STACK: u8 = 2;
+const PATH_TYPE_VDSO: u8 = 3;
+const PATH_TYPE_VVAR: u8 = 4;
+const PATH_TYPE_VSYSCALL: u8 = 5;
+const PATH_TYPE_FILE: u8 = 6;
+const PATH_TYPE_DELETED: u8 = 7;
+
+impl ToWriter for PathType {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        match self {
+            PathType::Anonymous => write_u8(writer, PATH_TYPE_ANONYMOUS),
+            PathType::Heap => write_u8(writer, PATH_TYPE_HEAP),
+            PathType::Stack => write_u8(writer, PATH_TYPE_STACK),
+            PathType::Vdso => write_u8(writer, PATH_TYPE_VDSO),
+            PathType::Vvar => write_u8(writer, PATH_TYPE_VVAR),
+            PathType::Vsyscall => write_u8(writer, PATH_TYPE_VSYSCALL),
+            PathType::File(path) => {
+                write_u8(writer, PATH_TYPE_FILE)?;
+                write_string(writer, &path.to_string_lossy())
+            }
+            PathType::Deleted(path) => {
+                write_u8(writer, PATH_TYPE_DELETED)?;
+                write_string(writer, &path.to_string_lossy())
+            }
+        }
+    }
+}
+
+impl FromReader for PathType {
+    fn from_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        Ok(match read_u8(reader)? {
+            PATH_TYPE_ANONYMOUS => PathType::Anonymous,
+            PATH_TYPE_HEAP => PathType::Heap,
+            PATH_TYPE_STACK => PathType::Stack,
+            PATH_TYPE_VDSO => PathType::Vdso,
+            PATH_TYPE_VVAR => PathType::Vvar,
+            PATH_TYPE_VSYSCALL => PathType::Vsyscall,
+            PATH_TYPE_FILE => PathType::File(PathBuf::from(read_string(reader)?)),
+            PATH_TYPE_DELETED => PathType::Deleted(PathBuf::from(read_string(reader)?)),
+            other => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("unknown PathType tag {other}"),
+                ));
+            }
+        })
+    }
+}
+
 impl fmt::Display for PathType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -123,7 +213,35 @@ pub struct MemoryRegion {
 
 impl MemoryRegion {
     fn size(&self) -> u64 {
-        return self.end - self.start;
+        self.end - self.start
+    }
+
+    pub(crate) fn start(&self) -> u64 {
+        self.start
+    }
+
+    pub(crate) fn end(&self) -> u64 {
+        self.end
+    }
+
+    pub(crate) fn permissions(&self) -> &Permissions {
+        &self.permissions
+    }
+
+    pub(crate) fn path_name(&self) -> Option<&PathType> {
+        self.path_name.as_ref()
+    }
+
+    pub(crate) fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    pub(crate) fn device(&self) -> (u8, u8) {
+        self.device
+    }
+
+    pub(crate) fn inode(&self) -> u64 {
+        self.inode
     }
 }
 
@@ -233,4 +351,98 @@ impl fmt::Display for MemoryRegion {
             self.path_name.as_ref().map_or(String::new(), |p| p.to_string()),
         )
     }
+}
+
+/// A `MemoryRegion` together with the per-region fields that only `/proc/{pid}/smaps`
+/// (and not the lighter-weight `/proc/{pid}/maps`) exposes.
+pub struct DetailedMemoryRegion {
+    region: MemoryRegion,
+    rss_kb: u64,
+}
+
+impl DetailedMemoryRegion {
+    pub(crate) fn from_region(region: MemoryRegion) -> Self {
+        Self { region, rss_kb: 0 }
+    }
+
+    pub(crate) fn region(&self) -> &MemoryRegion {
+        &self.region
+    }
+
+    pub(crate) fn rss_kb(&self) -> u64 {
+        self.rss_kb
+    }
+}
+
+impl ToWriter for MemoryRegion {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_u64(writer, self.start)?;
+        write_u64(writer, self.end)?;
+        self.permissions.to_writer(writer)?;
+        write_u64(writer, self.offset)?;
+        write_u8(writer, self.device.0)?;
+        write_u8(writer, self.device.1)?;
+        write_u64(writer, self.inode)?;
+        match &self.path_name {
+            Some(path_name) => {
+                write_u8(writer, 1)?;
+                path_name.to_writer(writer)?;
+            }
+            None => write_u8(writer, 0)?,
+        }
+        Ok(())
+    }
+}
+
+impl FromReader for MemoryRegion {
+    fn from_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let start = read_u64(reader)?;
+        let end = read_u64(reader)?;
+        let permissions = Permissions::from_reader(reader)?;
+        let offset = read_u64(reader)?;
+        let device = (read_u8(reader)?, read_u8(reader)?);
+        let inode = read_u64(reader)?;
+        let path_name = match read_u8(reader)? {
+            0 => None,
+            _ => Some(PathType::from_reader(reader)?),
+        };
+
+        Ok(MemoryRegion {
+            start,
+            end,
+            permissions,
+            offset,
+            device,
+            inode,
+            path_name,
+        })
+    }
+}
+
+/// Folds one `smaps` detail line into `region`. Only `Rss:` is recorded;
+/// every other key (e.g. `Size:`) is ignored.
+///
+/// Unrecognized keys are ignored so future kernels can add fields without
+/// breaking parsing.
+pub(crate) fn parse_detail_into_region(region: &mut DetailedMemoryRegion, line: &str) {
+    if let Some(rest) = line.strip_prefix("Rss:") {
+        if let Some(kb) = rest.trim().strip_suffix("kB").and_then(|s| s.trim().parse().ok()) {
+            region.rss_kb = kb;
+        }
+    }
+}
+
+impl ToWriter for DetailedMemoryRegion {
+    fn to_writer<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.region.to_writer(writer)?;
+        write_u64(writer, self.rss_kb)
+    }
+}
+
+impl FromReader for DetailedMemoryRegion {
+    fn from_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let region = MemoryRegion::from_reader(reader)?;
+        let rss_kb = read_u64(reader)?;
+        Ok(DetailedMemoryRegion { region, rss_kb })
+    }
 }
\ No newline at end of file
@@ -0,0 +1,4 @@
+mod proc;
+pub mod memory;
+
+pub use proc::Process;
@@ -0,0 +1,180 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::{fs, io, str::FromStr};
+
+use thiserror::Error;
+
+use crate::process::memory::{MemoryRegion, region::{MemoryParseError, DetailedMemoryRegion, PathType, parse_detail_into_region}};
+use crate::symbols::ElfSymbols;
+
+
+#[derive(Debug, Error)]
+pub enum ProcessParseError {
+    #[error("invalid integer: {0}")]
+    InvalidInt(#[from] std::num::ParseIntError),
+    #[error("Memory Parsing error: {0}")]
+    MemoryParseError(#[from] MemoryParseError),
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error)
+}
+
+pub struct Process {
+    pub pid: u32,
+    pub cmd_line: String,
+    pub memory_regions: Vec<DetailedMemoryRegion>,
+    /// One parsed `ElfSymbols` per backing file, keyed by `(device, inode)`
+    /// so a shared library mapped many times is only parsed once.
+    symbol_cache: RefCell<HashMap<(u8, u8, u64), Rc<ElfSymbols>>>,
+}
+
+impl TryFrom<u32> for Process {
+    type Error = ProcessParseError;
+
+    fn try_from(pid: u32) -> Result<Self, Self::Error> {
+        // get the string from cmdline
+        let cmd_line = fs::read_to_string(format!("/proc/{}/cmdline", pid))?
+            .replace('\0', " ")
+            .trim()
+            .to_string();
+
+        let smaps_content = fs::read_to_string(format!("/proc/{}/smaps", pid))?;
+        let mut lines = smaps_content.lines().peekable();
+        let mut memory_regions  = Vec::new();
+
+        while let Some(line) = lines.next() {
+            if is_address_line(line) {
+                let base_region = MemoryRegion::from_str(line)?;
+                let mut region = DetailedMemoryRegion::from_region(base_region);
+
+                while let Some(next) = lines.peek() {
+                    if is_address_line(next) {
+                        break;
+                    }
+                    let detail = lines.next().unwrap();
+                    parse_detail_into_region(&mut region, detail);
+                }
+                memory_regions.push(region);
+            }
+        }
+
+        Ok(Process {
+            pid,
+            cmd_line,
+            memory_regions,
+            symbol_cache: RefCell::new(HashMap::new()),
+        })
+    }
+
+}
+
+impl Process {
+    /// Reassembles a `Process` from fields recovered elsewhere, e.g. a
+    /// deserialized snapshot, rather than `/proc`.
+    pub(crate) fn from_parts(pid: u32, cmd_line: String, memory_regions: Vec<DetailedMemoryRegion>) -> Self {
+        Self {
+            pid,
+            cmd_line,
+            memory_regions,
+            symbol_cache: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// RSS in kB, summed per `path_name` display and sorted largest-first.
+    pub fn get_rss_totals(&self) -> Vec<(String, u64)> {
+        let mut totals: std::collections::HashMap<String, u64> = std::collections::HashMap::new();
+
+        for region in &self.memory_regions {
+            let name = region
+                .region()
+                .path_name()
+                .map_or_else(String::new, |p| p.to_string());
+            *totals.entry(name).or_insert(0) += region.rss_kb();
+        }
+
+        let mut totals: Vec<(String, u64)> = totals.into_iter().collect();
+        totals.sort_by_key(|t| std::cmp::Reverse(t.1));
+        totals
+    }
+
+    /// Resolves a process virtual address to the file, symbol name, and
+    /// displacement of the symbol backing it, e.g. `libc.so.6!malloc+0x3f`.
+    ///
+    /// Only file-backed regions can be resolved; anonymous mappings and
+    /// kernel-special regions (`[heap]`, `[stack]`, ...) return `None`.
+    pub fn resolve(&self, addr: u64) -> Option<(PathType, String, u64)> {
+        let region = self
+            .memory_regions
+            .iter()
+            .map(DetailedMemoryRegion::region)
+            .find(|region| addr >= region.start() && addr < region.end())?;
+
+        let path = match region.path_name() {
+            Some(PathType::File(path)) => path.clone(),
+            _ => return None,
+        };
+
+        let table = self.symbol_table(&path, region.device(), region.inode())?;
+
+        let file_offset = region.offset() + (addr - region.start());
+        let file_vaddr = table.file_offset_to_vaddr(file_offset)?;
+        let (name, displacement) = table.resolve(file_vaddr)?;
+
+        Some((PathType::File(path), name.to_string(), displacement))
+    }
+
+    fn symbol_table(
+        &self,
+        path: &std::path::Path,
+        device: (u8, u8),
+        inode: u64,
+    ) -> Option<Rc<ElfSymbols>> {
+        let key = (device.0, device.1, inode);
+        if let Some(table) = self.symbol_cache.borrow().get(&key) {
+            return Some(Rc::clone(table));
+        }
+
+        let table = Rc::new(ElfSymbols::load(path).ok()?);
+        self.symbol_cache.borrow_mut().insert(key, Rc::clone(&table));
+        Some(table)
+    }
+}
+
+/// True for an smaps region header (`<start>-<end> <perms> ...`), as
+/// opposed to one of the detail lines that follow it. Detail lines like
+/// `Anonymous:` or `AnonHugePages:` also start with a hex digit, so this
+/// checks the whole `<hex>-<hex> ` shape instead of just the first byte.
+fn is_address_line(line: &str) -> bool {
+    let Some((start, rest)) = line.split_once('-') else {
+        return false;
+    };
+    let Some((end, _)) = rest.split_once(' ') else {
+        return false;
+    };
+
+    !start.is_empty()
+        && !end.is_empty()
+        && start.chars().all(|c| c.is_ascii_hexdigit())
+        && end.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognizes_region_headers() {
+        assert!(is_address_line("00400000-00401000 r-xp 00000000 08:01 12345 /bin/true"));
+        assert!(is_address_line("7f0000000000-7f0000001000 rw-p 00000000 00:00 0 "));
+    }
+
+    #[test]
+    fn rejects_smaps_detail_lines_that_start_with_a_hex_digit() {
+        assert!(!is_address_line("Anonymous:             4 kB"));
+        assert!(!is_address_line("AnonHugePages:         0 kB"));
+        assert!(!is_address_line("FilePmdMapped:         0 kB"));
+        assert!(!is_address_line("Rss:                   4 kB"));
+    }
+}
+
+
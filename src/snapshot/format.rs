@@ -0,0 +1,56 @@
+use std::io::{self, Read, Write};
+
+/// Deserializes `Self` from the tool's fixed-width little-endian binary
+/// snapshot format.
+pub trait FromReader: Sized {
+    fn from_reader<R: Read>(reader: &mut R) -> io::Result<Self>;
+}
+
+/// Serializes `Self` into the tool's fixed-width little-endian binary
+/// snapshot format.
+pub trait ToWriter {
+    fn to_writer<W: Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+pub(crate) fn write_u8<W: Write>(writer: &mut W, value: u8) -> io::Result<()> {
+    writer.write_all(&[value])
+}
+
+pub(crate) fn read_u8<R: Read>(reader: &mut R) -> io::Result<u8> {
+    let mut buf = [0u8; 1];
+    reader.read_exact(&mut buf)?;
+    Ok(buf[0])
+}
+
+pub(crate) fn write_u32<W: Write>(writer: &mut W, value: u32) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+pub(crate) fn read_u32<R: Read>(reader: &mut R) -> io::Result<u32> {
+    let mut buf = [0u8; 4];
+    reader.read_exact(&mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+pub(crate) fn write_u64<W: Write>(writer: &mut W, value: u64) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+pub(crate) fn read_u64<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut buf = [0u8; 8];
+    reader.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+/// Writes a length-prefixed (u32 LE) UTF-8 string.
+pub(crate) fn write_string<W: Write>(writer: &mut W, value: &str) -> io::Result<()> {
+    write_u32(writer, value.len() as u32)?;
+    writer.write_all(value.as_bytes())
+}
+
+pub(crate) fn read_string<R: Read>(reader: &mut R) -> io::Result<String> {
+    let len = read_u32(reader)? as usize;
+    let mut buf = vec![0u8; len];
+    reader.read_exact(&mut buf)?;
+    String::from_utf8(buf).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
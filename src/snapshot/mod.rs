@@ -0,0 +1,12 @@
+//! Binary snapshot format for capturing a parsed [`Process`](crate::process::Process)
+//! to disk and reloading it for offline analysis.
+//!
+//! [`format`] defines the `FromReader`/`ToWriter` traits and the
+//! fixed-width little-endian primitives every type in this format is built
+//! from; [`process_snapshot`] frames a whole `Process` with a versioned
+//! header and writes it idempotently.
+
+pub mod format;
+mod process_snapshot;
+
+pub use process_snapshot::{SnapshotWrite, read_snapshot, write_snapshot};
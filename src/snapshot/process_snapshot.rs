@@ -0,0 +1,155 @@
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use thiserror::Error;
+
+use crate::hash::fnv1a64;
+use crate::process::Process;
+use crate::process::memory::region::DetailedMemoryRegion;
+
+use super::format::{FromReader, ToWriter, read_string, read_u32, write_string, write_u32};
+
+const MAGIC: &[u8; 4] = b"RMMP";
+const VERSION: u32 = 1;
+
+#[derive(Debug, Error)]
+pub enum SnapshotError {
+    #[error("io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("{0}: not a rs_memmap snapshot (bad magic)")]
+    BadMagic(PathBuf),
+    #[error("unsupported snapshot version {0}")]
+    UnsupportedVersion(u32),
+    #[error("{0}: modified on disk after it was last read; refusing to overwrite")]
+    StaleWrite(PathBuf),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotWrite {
+    /// The snapshot file was created or overwritten.
+    Written,
+    /// The freshly serialized bytes were identical to what's already on
+    /// disk, so the write was skipped.
+    UnchangedSkipped,
+}
+
+/// Writes `process` to `path` in the snapshot format, idempotently.
+///
+/// If `read_at` is `Some` (the caller previously loaded `path` via
+/// [`read_snapshot`] and is writing back an updated snapshot), and the
+/// file's mtime is newer than `read_at`, the write is refused: something
+/// else touched the file in the meantime and blindly overwriting it would
+/// clobber that change. Otherwise, the freshly serialized bytes are hashed
+/// and compared against the existing file's contents, and the write is
+/// skipped entirely when they match, so snapshotting at an interval
+/// doesn't churn identical files.
+pub fn write_snapshot(
+    path: &Path,
+    process: &Process,
+    read_at: Option<SystemTime>,
+) -> Result<SnapshotWrite, SnapshotError> {
+    if let Some(read_at) = read_at {
+        if let Ok(metadata) = fs::metadata(path) {
+            if metadata.modified()? > read_at {
+                return Err(SnapshotError::StaleWrite(path.to_path_buf()));
+            }
+        }
+    }
+
+    let mut bytes = Vec::new();
+    encode(process, &mut bytes)?;
+
+    if let Ok(existing) = fs::read(path) {
+        if fnv1a64(&existing) == fnv1a64(&bytes) && existing == bytes {
+            return Ok(SnapshotWrite::UnchangedSkipped);
+        }
+    }
+
+    fs::write(path, &bytes)?;
+    Ok(SnapshotWrite::Written)
+}
+
+/// Loads a `Process` snapshot from `path`, returning it alongside the
+/// file's mtime at read time so a later [`write_snapshot`] can detect
+/// concurrent modification.
+pub fn read_snapshot(path: &Path) -> Result<(Process, SystemTime), SnapshotError> {
+    let read_at = fs::metadata(path)?.modified()?;
+    let bytes = fs::read(path)?;
+    let mut cursor = &bytes[..];
+    let process = decode(&mut cursor, path)?;
+    Ok((process, read_at))
+}
+
+fn encode(process: &Process, out: &mut Vec<u8>) -> io::Result<()> {
+    out.write_all(MAGIC)?;
+    write_u32(out, VERSION)?;
+    write_u32(out, process.pid)?;
+    write_string(out, &process.cmd_line)?;
+    write_u32(out, process.memory_regions.len() as u32)?;
+    for region in &process.memory_regions {
+        region.to_writer(out)?;
+    }
+    Ok(())
+}
+
+fn decode(reader: &mut &[u8], path: &Path) -> Result<Process, SnapshotError> {
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(SnapshotError::BadMagic(path.to_path_buf()));
+    }
+
+    let version = read_u32(reader)?;
+    if version != VERSION {
+        return Err(SnapshotError::UnsupportedVersion(version));
+    }
+
+    let pid = read_u32(reader)?;
+    let cmd_line = read_string(reader)?;
+    let region_count = read_u32(reader)? as usize;
+    let mut memory_regions = Vec::with_capacity(region_count);
+    for _ in 0..region_count {
+        memory_regions.push(DetailedMemoryRegion::from_reader(reader)?);
+    }
+
+    Ok(Process::from_parts(pid, cmd_line, memory_regions))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::process::memory::region::{MemoryRegion, parse_detail_into_region};
+
+    #[test]
+    fn round_trips_encode_and_decode() {
+        let region = MemoryRegion::from_str(
+            "00400000-00401000 r-xp 00000000 08:01 12345 /bin/true",
+        )
+        .unwrap();
+        let mut detailed = DetailedMemoryRegion::from_region(region);
+        parse_detail_into_region(&mut detailed, "Rss:                  4 kB");
+
+        let process = Process::from_parts(1234, "/bin/true".to_string(), vec![detailed]);
+
+        let mut bytes = Vec::new();
+        encode(&process, &mut bytes).unwrap();
+
+        let decoded = decode(&mut &bytes[..], Path::new("test")).unwrap();
+        assert_eq!(decoded.pid, 1234);
+        assert_eq!(decoded.cmd_line, "/bin/true");
+        assert_eq!(decoded.memory_regions.len(), 1);
+        assert_eq!(decoded.memory_regions[0].rss_kb(), 4);
+        assert_eq!(decoded.memory_regions[0].region().start(), 0x400000);
+    }
+
+    #[test]
+    fn rejects_bad_magic() {
+        let bytes = [0u8; 16];
+        let is_bad_magic = matches!(decode(&mut &bytes[..], Path::new("test")), Err(SnapshotError::BadMagic(_)));
+        assert!(is_bad_magic);
+    }
+}
@@ -0,0 +1,253 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const EI_NIDENT: usize = 16;
+const ELF_MAGIC: &[u8] = b"\x7fELF";
+const ELFCLASS64: u8 = 2;
+
+const SHT_SYMTAB: u32 = 2;
+const SHT_DYNSYM: u32 = 11;
+const PT_LOAD: u32 = 1;
+
+struct ProgramHeader {
+    vaddr: u64,
+    offset: u64,
+    filesz: u64,
+}
+
+struct Symbol {
+    name: String,
+    value: u64,
+    size: u64,
+}
+
+/// The subset of an ELF64 file this tool needs to turn a file offset into a
+/// symbol name: its `PT_LOAD` segments (to translate a file offset back to
+/// the vaddr the ELF itself was linked at) and its `.symtab`/`.dynsym`
+/// entries (to name that vaddr).
+pub struct ElfSymbols {
+    program_headers: Vec<ProgramHeader>,
+    symbols: Vec<Symbol>,
+}
+
+impl ElfSymbols {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let data = fs::read(path)?;
+        parse(&data).ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "not a 64-bit ELF"))
+    }
+
+    /// Translates a file offset (as seen in `/proc/{pid}/maps`) to the
+    /// virtual address the ELF was linked at, via the `PT_LOAD` segment
+    /// that covers it.
+    pub fn file_offset_to_vaddr(&self, file_offset: u64) -> Option<u64> {
+        self.program_headers
+            .iter()
+            .find(|ph| file_offset >= ph.offset && file_offset < ph.offset + ph.filesz)
+            .map(|ph| ph.vaddr + (file_offset - ph.offset))
+    }
+
+    /// Finds the symbol enclosing `vaddr`, returning its name and the
+    /// displacement of `vaddr` from the symbol's start.
+    pub fn resolve(&self, vaddr: u64) -> Option<(&str, u64)> {
+        let idx = match self.symbols.binary_search_by_key(&vaddr, |s| s.value) {
+            Ok(idx) => idx,
+            Err(0) => return None,
+            Err(idx) => idx - 1,
+        };
+
+        let symbol = &self.symbols[idx];
+        if symbol.size != 0 && vaddr >= symbol.value + symbol.size {
+            return None;
+        }
+
+        Some((&symbol.name, vaddr - symbol.value))
+    }
+}
+
+fn parse(data: &[u8]) -> Option<ElfSymbols> {
+    if data.len() < EI_NIDENT || &data[0..4] != ELF_MAGIC || data[4] != ELFCLASS64 {
+        return None;
+    }
+
+    let e_phoff = read_u64(data, 32)?;
+    let e_shoff = read_u64(data, 40)?;
+    let e_phentsize = read_u16(data, 54)? as usize;
+    let e_phnum = read_u16(data, 56)? as usize;
+    let e_shentsize = read_u16(data, 58)? as usize;
+    let e_shnum = read_u16(data, 60)? as usize;
+
+    let mut program_headers = Vec::with_capacity(e_phnum);
+    for i in 0..e_phnum {
+        let base = e_phoff as usize + i * e_phentsize;
+        let p_type = read_u32(data, base)?;
+        if p_type != PT_LOAD {
+            continue;
+        }
+        program_headers.push(ProgramHeader {
+            offset: read_u64(data, base + 8)?,
+            vaddr: read_u64(data, base + 16)?,
+            filesz: read_u64(data, base + 32)?,
+        });
+    }
+
+    let mut symbols = Vec::new();
+    for i in 0..e_shnum {
+        let base = e_shoff as usize + i * e_shentsize;
+        let sh_type = read_u32(data, base + 4)?;
+        if sh_type != SHT_SYMTAB && sh_type != SHT_DYNSYM {
+            continue;
+        }
+
+        let sh_offset = read_u64(data, base + 24)? as usize;
+        let sh_size = read_u64(data, base + 32)? as usize;
+        let sh_link = read_u32(data, base + 40)? as usize;
+        let sh_entsize = read_u64(data, base + 56)? as usize;
+        if sh_entsize == 0 {
+            continue;
+        }
+
+        let strtab_base = e_shoff as usize + sh_link * e_shentsize;
+        let strtab_offset = read_u64(data, strtab_base + 24)? as usize;
+        let strtab_size = read_u64(data, strtab_base + 32)? as usize;
+        let strtab = data.get(strtab_offset..strtab_offset + strtab_size)?;
+
+        let count = sh_size / sh_entsize;
+        for j in 0..count {
+            let sym_base = sh_offset + j * sh_entsize;
+            let st_name = read_u32(data, sym_base)? as usize;
+            let st_value = read_u64(data, sym_base + 8)?;
+            let st_size = read_u64(data, sym_base + 16)?;
+            if st_value == 0 {
+                continue;
+            }
+
+            if let Some(name) = read_cstr(strtab, st_name) {
+                if !name.is_empty() {
+                    symbols.push(Symbol {
+                        name: name.to_string(),
+                        value: st_value,
+                        size: st_size,
+                    });
+                }
+            }
+        }
+    }
+
+    symbols.sort_by_key(|s| s.value);
+    symbols.dedup_by_key(|s| s.value);
+
+    Some(ElfSymbols {
+        program_headers,
+        symbols,
+    })
+}
+
+fn read_cstr(strtab: &[u8], offset: usize) -> Option<&str> {
+    let bytes = strtab.get(offset..)?;
+    let len = bytes.iter().position(|&b| b == 0)?;
+    std::str::from_utf8(&bytes[..len]).ok()
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    data.get(offset..offset + 2)
+        .map(|b| u16::from_le_bytes(b.try_into().unwrap()))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset + 4)
+        .map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    data.get(offset..offset + 8)
+        .map(|b| u64::from_le_bytes(b.try_into().unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn put_u16(buf: &mut [u8], offset: usize, value: u16) {
+        buf[offset..offset + 2].copy_from_slice(&value.to_le_bytes());
+    }
+
+    fn put_u32(buf: &mut [u8], offset: usize, value: u32) {
+        buf[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+    }
+
+    fn put_u64(buf: &mut [u8], offset: usize, value: u64) {
+        buf[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+    }
+
+    /// Hand-assembles a minimal ELF64 file with one `PT_LOAD` segment and
+    /// one symbol (`myfunc`, value `0x400010`, size `0x20`), laid out at
+    /// fixed offsets: header, program header, string table, symbol table,
+    /// then the section header table.
+    fn build_elf() -> Vec<u8> {
+        const EHDR_SIZE: usize = 64;
+        const PHDR_OFF: usize = EHDR_SIZE;
+        const PHDR_SIZE: usize = 56;
+        const STRTAB_OFF: usize = PHDR_OFF + PHDR_SIZE;
+        const STRTAB: &[u8] = b"\0myfunc\0";
+        const SYMTAB_OFF: usize = STRTAB_OFF + STRTAB.len();
+        const SYM_SIZE: usize = 24;
+        const SHDR_OFF: usize = SYMTAB_OFF + SYM_SIZE;
+        const SHDR_SIZE: usize = 64;
+        const FILE_LEN: usize = SHDR_OFF + 2 * SHDR_SIZE;
+
+        let mut buf = vec![0u8; FILE_LEN];
+        buf[0..4].copy_from_slice(ELF_MAGIC);
+        buf[4] = ELFCLASS64;
+        put_u64(&mut buf, 32, PHDR_OFF as u64); // e_phoff
+        put_u64(&mut buf, 40, SHDR_OFF as u64); // e_shoff
+        put_u16(&mut buf, 54, PHDR_SIZE as u16); // e_phentsize
+        put_u16(&mut buf, 56, 1); // e_phnum
+        put_u16(&mut buf, 58, SHDR_SIZE as u16); // e_shentsize
+        put_u16(&mut buf, 60, 2); // e_shnum
+
+        // One PT_LOAD segment covering the whole file, linked at 0x400000.
+        put_u32(&mut buf, PHDR_OFF, PT_LOAD);
+        put_u64(&mut buf, PHDR_OFF + 8, 0); // p_offset
+        put_u64(&mut buf, PHDR_OFF + 16, 0x400000); // p_vaddr
+        put_u64(&mut buf, PHDR_OFF + 32, FILE_LEN as u64); // p_filesz
+
+        buf[STRTAB_OFF..STRTAB_OFF + STRTAB.len()].copy_from_slice(STRTAB);
+
+        put_u32(&mut buf, SYMTAB_OFF, 1); // st_name -> "myfunc"
+        put_u64(&mut buf, SYMTAB_OFF + 8, 0x400010); // st_value
+        put_u64(&mut buf, SYMTAB_OFF + 16, 0x20); // st_size
+
+        // Section 0: string table.
+        put_u64(&mut buf, SHDR_OFF + 24, STRTAB_OFF as u64); // sh_offset
+        put_u64(&mut buf, SHDR_OFF + 32, STRTAB.len() as u64); // sh_size
+
+        // Section 1: symbol table, linked to section 0.
+        let sym_shdr = SHDR_OFF + SHDR_SIZE;
+        put_u32(&mut buf, sym_shdr + 4, SHT_SYMTAB); // sh_type
+        put_u64(&mut buf, sym_shdr + 24, SYMTAB_OFF as u64); // sh_offset
+        put_u64(&mut buf, sym_shdr + 32, SYM_SIZE as u64); // sh_size
+        put_u32(&mut buf, sym_shdr + 40, 0); // sh_link -> section 0
+        put_u64(&mut buf, sym_shdr + 56, SYM_SIZE as u64); // sh_entsize
+
+        buf
+    }
+
+    #[test]
+    fn round_trips_a_crafted_elf() {
+        let path = std::env::temp_dir().join(format!("rs_memmap_elf_test_{}.bin", std::process::id()));
+        std::fs::write(&path, build_elf()).unwrap();
+
+        let result = ElfSymbols::load(&path);
+        std::fs::remove_file(&path).ok();
+        let symbols = result.unwrap();
+
+        assert_eq!(symbols.file_offset_to_vaddr(0x10), Some(0x400010));
+
+        let (name, displacement) = symbols.resolve(0x400018).unwrap();
+        assert_eq!(name, "myfunc");
+        assert_eq!(displacement, 0x8);
+
+        assert_eq!(symbols.resolve(0x400000), None);
+    }
+}
@@ -0,0 +1,11 @@
+//! Address-to-symbol resolution for file-backed memory regions.
+//!
+//! [`elf`] parses just enough of an ELF64 file (its `PT_LOAD` segments and
+//! `.symtab`/`.dynsym`) to turn a file offset into `(symbol name,
+//! displacement)`. [`Process::resolve`](crate::process::Process::resolve)
+//! drives it per-region and caches one [`ElfSymbols`] per `(device, inode)`
+//! so a shared library mapped many times is only parsed once.
+
+mod elf;
+
+pub use elf::ElfSymbols;